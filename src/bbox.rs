@@ -0,0 +1,154 @@
+use std::f32::consts::PI;
+
+use crate::geometry::{self, AbsoluteSegment, ArcCenter, Point};
+use crate::Path;
+
+impl Path {
+    /// The exact bounding box of this path's geometry, as `(min_x, min_y, max_x, max_y)`, or
+    /// `None` if the path has no segments. Unlike a control-point hull, this accounts for the
+    /// true extrema of every curve and arc, not just their endpoints and control points.
+    pub fn bounding_box(&self) -> Option<(f32, f32, f32, f32)> {
+        let mut bbox: Option<(f32, f32, f32, f32)> = None;
+
+        for resolved in geometry::resolve(self.segments()) {
+            // A move's starting point is not part of the drawn geometry.
+            if !matches!(resolved.segment, AbsoluteSegment::MoveTo(_)) {
+                include(&mut bbox, resolved.from);
+            }
+            match resolved.segment {
+                AbsoluteSegment::MoveTo(p) | AbsoluteSegment::LineTo(p) | AbsoluteSegment::Close(p) =>
+                    include(&mut bbox, p),
+                AbsoluteSegment::CubicTo(p1, p2, p3) => {
+                    include(&mut bbox, p3);
+                    for t in cubic_extrema(resolved.from, p1, p2, p3) {
+                        include(&mut bbox, geometry::subdivide_cubic(resolved.from, p1, p2, p3, t).0.3);
+                    }
+                }
+                AbsoluteSegment::QuadraticTo(p1, p2) => {
+                    include(&mut bbox, p2);
+                    for t in quadratic_extrema(resolved.from, p1, p2) {
+                        include(&mut bbox, geometry::subdivide_quadratic(resolved.from, p1, p2, t).0.2);
+                    }
+                }
+                AbsoluteSegment::ArcTo { rx, ry, xrot, large_arc, sweep, to } => {
+                    include(&mut bbox, to);
+                    if let Some(arc) = geometry::arc_to_center(resolved.from, rx, ry, xrot, large_arc, sweep, to) {
+                        for angle in arc_extrema_angles(&arc) {
+                            if angle_in_sweep(angle, arc.start_angle, arc.delta_angle) {
+                                include(&mut bbox, arc.point_at_angle(angle));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        bbox
+    }
+}
+
+fn include(bbox: &mut Option<(f32, f32, f32, f32)>, (x, y): Point) {
+    *bbox = Some(match *bbox {
+        None => (x, y, x, y),
+        Some((min_x, min_y, max_x, max_y)) =>
+            (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y)),
+    });
+}
+
+/// The `t` values in `(0, 1)` at which the cubic bezier `p0, p1, p2, p3` has a horizontal or
+/// vertical tangent, found by solving the quadratic `3(1-t)^2(P1-P0) + 6(1-t)t(P2-P1) +
+/// 3t^2(P3-P2) = 0` per axis.
+fn cubic_extrema(p0: Point, p1: Point, p2: Point, p3: Point) -> Vec<f32> {
+    let axis = |p0: f32, p1: f32, p2: f32, p3: f32| -> Vec<f32> {
+        let a = -p0 + 3.0 * p1 - 3.0 * p2 + p3;
+        let b = 2.0 * p0 - 4.0 * p1 + 2.0 * p2;
+        let c = p1 - p0;
+        solve_quadratic(a, b, c).into_iter().filter(|t| *t > 0.0 && *t < 1.0).collect()
+    };
+    let mut ts = axis(p0.0, p1.0, p2.0, p3.0);
+    ts.extend(axis(p0.1, p1.1, p2.1, p3.1));
+    ts
+}
+
+/// The `t` values in `(0, 1)` at which the quadratic bezier `p0, p1, p2` has a horizontal or
+/// vertical tangent.
+fn quadratic_extrema(p0: Point, p1: Point, p2: Point) -> Vec<f32> {
+    let axis = |p0: f32, p1: f32, p2: f32| -> Option<f32> {
+        let denom = p0 - 2.0 * p1 + p2;
+        if denom.abs() < f32::EPSILON {
+            return None;
+        }
+        let t = (p0 - p1) / denom;
+        (t > 0.0 && t < 1.0).then_some(t)
+    };
+    [axis(p0.0, p1.0, p2.0), axis(p0.1, p1.1, p2.1)].into_iter().flatten().collect()
+}
+
+fn solve_quadratic(a: f32, b: f32, c: f32) -> Vec<f32> {
+    if a.abs() < f32::EPSILON {
+        if b.abs() < f32::EPSILON { vec![] } else { vec![-c / b] }
+    } else {
+        let discriminant = b * b - 4.0 * a * c;
+        if discriminant < 0.0 {
+            vec![]
+        } else {
+            let sqrt_discriminant = discriminant.sqrt();
+            vec![(-b + sqrt_discriminant) / (2.0 * a), (-b - sqrt_discriminant) / (2.0 * a)]
+        }
+    }
+}
+
+/// The (unrotated-parameter) angles at which the ellipse's x and y coordinates are stationary:
+/// two candidates 180 degrees apart per axis.
+fn arc_extrema_angles(arc: &ArcCenter) -> [f32; 4] {
+    let (sin_phi, cos_phi) = arc.xrot.sin_cos();
+    let theta_x = (-arc.ry * sin_phi).atan2(arc.rx * cos_phi);
+    let theta_y = (arc.ry * cos_phi).atan2(arc.rx * sin_phi);
+    [theta_x, theta_x + PI, theta_y, theta_y + PI]
+}
+
+/// Whether `angle` falls within the arc's swept range, walking from `start` through `delta`
+/// (which may be negative for a clockwise sweep).
+fn angle_in_sweep(angle: f32, start: f32, delta: f32) -> bool {
+    let two_pi = 2.0 * PI;
+    let mut offset = (angle - start) % two_pi;
+    if offset < 0.0 {
+        offset += two_pi;
+    }
+    if delta >= 0.0 {
+        offset <= delta
+    } else {
+        offset - two_pi >= delta
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Path;
+
+    fn assert_close((ax, ay, aw, az): (f32, f32, f32, f32), (bx, by, bw, bz): (f32, f32, f32, f32)) {
+        let within = |a: f32, b: f32| (a - b).abs() < 1e-3;
+        assert!(within(ax, bx) && within(ay, by) && within(aw, bw) && within(az, bz),
+            "({}, {}, {}, {}) != ({}, {}, {}, {})", ax, ay, aw, az, bx, by, bw, bz);
+    }
+
+    #[test]
+    fn an_empty_path_has_no_bounding_box() {
+        assert_eq!(Path::new().bounding_box(), None);
+    }
+
+    #[test]
+    fn a_circles_bounding_box_is_its_enclosing_square() {
+        let bbox = Path::circle(0.0, 0.0, 5.0).bounding_box().unwrap();
+        assert_close(bbox, (-5.0, -5.0, 5.0, 5.0));
+    }
+
+    #[test]
+    fn a_cubics_bounding_box_includes_its_true_extremum_not_just_its_control_points() {
+        // A symmetric arch whose control points sit well outside the endpoint-only hull.
+        let path = Path::new().move_to(0.0, 0.0).cubic_bezier_to(0.0, 3.0, 10.0, 3.0, 10.0, 0.0);
+        let (_, min_y, _, max_y) = path.bounding_box().unwrap();
+        assert!(min_y >= 0.0, "the curve never dips below its endpoints");
+        assert!(max_y > 0.0 && max_y < 3.0, "the true extremum is between the endpoints and the control points");
+    }
+}
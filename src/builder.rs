@@ -0,0 +1,337 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::geometry::{self, Point};
+use crate::Path;
+
+/// An error produced by a [`SvgPathBuilder`] method that cannot be satisfied given the path
+/// built so far.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BuilderError {
+    /// The first segment of a path must be a move command.
+    MissingInitialMoveTo,
+    /// A smooth curve command requires the previous segment to have been a curve of the same
+    /// kind (cubic for `smooth_cubic_bezier_*`, quadratic for `smooth_quadratic_bezier_*`).
+    NotAContinuation,
+}
+
+impl Display for BuilderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            BuilderError::MissingInitialMoveTo =>
+                write!(f, "a path must start with a move_to or move_by command"),
+            BuilderError::NotAContinuation =>
+                write!(f, "a smooth curve command requires the previous segment to be a curve of the same kind"),
+        }
+    }
+}
+
+impl Error for BuilderError {}
+
+/// A current-point-aware layer over [`Path`]'s stateless builder methods. Where the stateless
+/// methods on `Path` emit SVG commands without tracking any state (mirroring the `S`/`s`/`T`/`t`
+/// commands' spec-defined fallback of using the current point as the control point when there is
+/// no matching previous curve, which can silently produce the wrong geometry), `SvgPathBuilder`
+/// tracks the current point, subpath start, and last control point so it can reject a path that
+/// doesn't start with a move and compute smooth-curve reflections itself, erroring rather than
+/// silently falling back when there is nothing valid to reflect.
+///
+/// This mirrors the split lyon draws between its low-level `PathBuilder` and stateful
+/// `SvgPathBuilder`; use [`Path`]'s own methods directly when you don't need the validation.
+#[derive(Clone, Debug)]
+pub struct SvgPathBuilder {
+    path: Path,
+    current_point: Option<Point>,
+    subpath_start: Point,
+    last_cubic_control: Option<Point>,
+    last_quadratic_control: Option<Point>,
+}
+
+impl SvgPathBuilder {
+    /// Create an empty builder.
+    pub fn new() -> SvgPathBuilder {
+        SvgPathBuilder {
+            path: Path::new(),
+            current_point: None,
+            subpath_start: (0.0, 0.0),
+            last_cubic_control: None,
+            last_quadratic_control: None,
+        }
+    }
+
+    /// The current point, or `None` if no move command has been issued yet.
+    pub fn current_point(&self) -> Option<Point> {
+        self.current_point
+    }
+
+    /// The control point that a following smooth curve command would reflect, or `None` if the
+    /// previous segment was not a curve.
+    pub fn last_control_point(&self) -> Option<Point> {
+        self.last_cubic_control.or(self.last_quadratic_control)
+    }
+
+    /// Finish building and return the underlying path.
+    pub fn build(self) -> Path {
+        self.path
+    }
+
+    fn require_started(&self) -> Result<Point, BuilderError> {
+        self.current_point.ok_or(BuilderError::MissingInitialMoveTo)
+    }
+
+    /// Move the current point to `(x, y)` and set it as the initial point of a new subpath.
+    pub fn move_to(mut self, x: f32, y: f32) -> SvgPathBuilder {
+        self.path = self.path.move_to(x, y);
+        self.start_subpath_at((x, y));
+        self
+    }
+
+    /// Move the current point by `(dx, dy)` and set it as the initial point of a new subpath.
+    pub fn move_by(mut self, dx: f32, dy: f32) -> SvgPathBuilder {
+        let to = geometry::resolve_point(false, self.current_point.unwrap_or((0.0, 0.0)), dx, dy);
+        self.path = self.path.move_by(dx, dy);
+        self.start_subpath_at(to);
+        self
+    }
+
+    fn start_subpath_at(&mut self, p: Point) {
+        self.current_point = Some(p);
+        self.subpath_start = p;
+        self.last_cubic_control = None;
+        self.last_quadratic_control = None;
+    }
+
+    /// Draw a line to the point `(x, y)`.
+    pub fn line_to(mut self, x: f32, y: f32) -> Result<SvgPathBuilder, BuilderError> {
+        self.require_started()?;
+        self.path = self.path.line_to(x, y);
+        self.advance_to((x, y));
+        Ok(self)
+    }
+
+    /// Draw a line of length `(dx, dy)` from the current point.
+    pub fn line_by(mut self, dx: f32, dy: f32) -> Result<SvgPathBuilder, BuilderError> {
+        let current = self.require_started()?;
+        self.path = self.path.line_by(dx, dy);
+        self.advance_to(geometry::resolve_point(false, current, dx, dy));
+        Ok(self)
+    }
+
+    /// Draw a horizontal line from the current point to the specified x-coordinate.
+    pub fn horizontal_line_to(mut self, x: f32) -> Result<SvgPathBuilder, BuilderError> {
+        let current = self.require_started()?;
+        self.path = self.path.horizontal_line_to(x);
+        self.advance_to((x, current.1));
+        Ok(self)
+    }
+
+    /// Draw a horizontal line of length `dx` from the current point.
+    pub fn horizontal_line_by(mut self, dx: f32) -> Result<SvgPathBuilder, BuilderError> {
+        let current = self.require_started()?;
+        self.path = self.path.horizontal_line_by(dx);
+        self.advance_to((current.0 + dx, current.1));
+        Ok(self)
+    }
+
+    /// Draw a vertical line from the current point to the specified y-coordinate.
+    pub fn vertical_line_to(mut self, y: f32) -> Result<SvgPathBuilder, BuilderError> {
+        let current = self.require_started()?;
+        self.path = self.path.vertical_line_to(y);
+        self.advance_to((current.0, y));
+        Ok(self)
+    }
+
+    /// Draw a vertical line of length `dy` from the current point.
+    pub fn vertical_line_by(mut self, dy: f32) -> Result<SvgPathBuilder, BuilderError> {
+        let current = self.require_started()?;
+        self.path = self.path.vertical_line_by(dy);
+        self.advance_to((current.0, current.1 + dy));
+        Ok(self)
+    }
+
+    /// Close the current subpath by drawing a line to its initial point.
+    pub fn close(mut self) -> Result<SvgPathBuilder, BuilderError> {
+        self.require_started()?;
+        self.path = self.path.close();
+        self.advance_to(self.subpath_start);
+        Ok(self)
+    }
+
+    fn advance_to(&mut self, p: Point) {
+        self.current_point = Some(p);
+        self.last_cubic_control = None;
+        self.last_quadratic_control = None;
+    }
+
+    /// Draw a cubic bezier curve from the current point to `(x, y)`, with control points at
+    /// `(x1, y1)` and `(x2, y2)`.
+    pub fn cubic_bezier_to(mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) -> Result<SvgPathBuilder, BuilderError> {
+        self.require_started()?;
+        self.path = self.path.cubic_bezier_to(x1, y1, x2, y2, x, y);
+        self.advance_through_cubic((x2, y2), (x, y));
+        Ok(self)
+    }
+
+    /// Draw a cubic bezier curve from the current point to the point located `(dx, dy)` away,
+    /// with control points at `(dx1, dy1)` and `(dx2, dy2)` relative to the current point.
+    pub fn cubic_bezier_by(mut self, dx1: f32, dy1: f32, dx2: f32, dy2: f32, dx: f32, dy: f32) -> Result<SvgPathBuilder, BuilderError> {
+        let current = self.require_started()?;
+        self.path = self.path.cubic_bezier_by(dx1, dy1, dx2, dy2, dx, dy);
+        self.advance_through_cubic(
+            geometry::resolve_point(false, current, dx2, dy2),
+            geometry::resolve_point(false, current, dx, dy),
+        );
+        Ok(self)
+    }
+
+    /// Draw a cubic bezier curve from the current point to `(x, y)`, with its first control
+    /// point computed as the reflection of the previous curve's second control point through
+    /// the current point, and its second control point at `(x2, y2)`.
+    pub fn smooth_cubic_bezier_to(mut self, x2: f32, y2: f32, x: f32, y: f32) -> Result<SvgPathBuilder, BuilderError> {
+        let current = self.require_started()?;
+        let c1 = geometry::reflect(current, self.last_cubic_control.ok_or(BuilderError::NotAContinuation)?);
+        self.path = self.path.cubic_bezier_to(c1.0, c1.1, x2, y2, x, y);
+        self.advance_through_cubic((x2, y2), (x, y));
+        Ok(self)
+    }
+
+    /// Draw a cubic bezier curve from the current point to the point located `(dx, dy)` away,
+    /// with its first control point computed as the reflection of the previous curve's second
+    /// control point through the current point, and its second control point `(dx2, dy2)` away
+    /// from the current point.
+    pub fn smooth_cubic_bezier_by(mut self, dx2: f32, dy2: f32, dx: f32, dy: f32) -> Result<SvgPathBuilder, BuilderError> {
+        let current = self.require_started()?;
+        let c1 = geometry::reflect(current, self.last_cubic_control.ok_or(BuilderError::NotAContinuation)?);
+        self.path = self.path.cubic_bezier_by(c1.0 - current.0, c1.1 - current.1, dx2, dy2, dx, dy);
+        self.advance_through_cubic(
+            geometry::resolve_point(false, current, dx2, dy2),
+            geometry::resolve_point(false, current, dx, dy),
+        );
+        Ok(self)
+    }
+
+    fn advance_through_cubic(&mut self, c2: Point, to: Point) {
+        self.current_point = Some(to);
+        self.last_cubic_control = Some(c2);
+        self.last_quadratic_control = None;
+    }
+
+    /// Draw a quadratic bezier curve from the current point to `(x, y)`, with a control point
+    /// at `(x1, y1)`.
+    pub fn quadratic_bezier_to(mut self, x1: f32, y1: f32, x: f32, y: f32) -> Result<SvgPathBuilder, BuilderError> {
+        self.require_started()?;
+        self.path = self.path.quadratic_bezier_to(x1, y1, x, y);
+        self.advance_through_quadratic((x1, y1), (x, y));
+        Ok(self)
+    }
+
+    /// Draw a quadratic bezier curve from the current point to the point located `(dx, dy)`
+    /// away, with control point located `(dx1, dy1)` away from the current point.
+    pub fn quadratic_bezier_by(mut self, dx1: f32, dy1: f32, dx: f32, dy: f32) -> Result<SvgPathBuilder, BuilderError> {
+        let current = self.require_started()?;
+        self.path = self.path.quadratic_bezier_by(dx1, dy1, dx, dy);
+        self.advance_through_quadratic(
+            geometry::resolve_point(false, current, dx1, dy1),
+            geometry::resolve_point(false, current, dx, dy),
+        );
+        Ok(self)
+    }
+
+    /// Draw a quadratic bezier curve from the current point to `(x, y)`, with its control point
+    /// computed as the reflection of the previous curve's control point through the current
+    /// point.
+    pub fn smooth_quadratic_bezier_to(mut self, x: f32, y: f32) -> Result<SvgPathBuilder, BuilderError> {
+        let current = self.require_started()?;
+        let c1 = geometry::reflect(current, self.last_quadratic_control.ok_or(BuilderError::NotAContinuation)?);
+        self.path = self.path.quadratic_bezier_to(c1.0, c1.1, x, y);
+        self.advance_through_quadratic(c1, (x, y));
+        Ok(self)
+    }
+
+    /// Draw a quadratic bezier curve from the current point to the point located `(dx, dy)`
+    /// away, with its control point computed as the reflection of the previous curve's control
+    /// point through the current point.
+    pub fn smooth_quadratic_bezier_by(mut self, dx: f32, dy: f32) -> Result<SvgPathBuilder, BuilderError> {
+        let current = self.require_started()?;
+        let c1 = geometry::reflect(current, self.last_quadratic_control.ok_or(BuilderError::NotAContinuation)?);
+        self.path = self.path.quadratic_bezier_by(c1.0 - current.0, c1.1 - current.1, dx, dy);
+        self.advance_through_quadratic(c1, geometry::resolve_point(false, current, dx, dy));
+        Ok(self)
+    }
+
+    fn advance_through_quadratic(&mut self, c1: Point, to: Point) {
+        self.current_point = Some(to);
+        self.last_quadratic_control = Some(c1);
+        self.last_cubic_control = None;
+    }
+
+    /// Draw an elliptical arc beginning at the current point and ending at `(x, y)`. See
+    /// [`Path::elliptical_arc_to`] for the meaning of `rx`, `ry`, `xrot`, `large_arc`, and `sweep`.
+    // Mirrors Path::elliptical_arc_to's argument list one-for-one, which already carries the
+    // same count.
+    #[allow(clippy::too_many_arguments)]
+    pub fn elliptical_arc_to(mut self, rx: f32, ry: f32, xrot: f32, large_arc: bool, sweep: bool, x: f32, y: f32) -> Result<SvgPathBuilder, BuilderError> {
+        self.require_started()?;
+        self.path = self.path.elliptical_arc_to(rx, ry, xrot, large_arc, sweep, x, y);
+        self.advance_to((x, y));
+        Ok(self)
+    }
+
+    /// Draw an elliptical arc beginning at the current point and ending at the point `(dx, dy)`
+    /// away. See [`Path::elliptical_arc_by`] for the meaning of `rx`, `ry`, `xrot`, `large_arc`,
+    /// and `sweep`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn elliptical_arc_by(mut self, rx: f32, ry: f32, xrot: f32, large_arc: bool, sweep: bool, dx: f32, dy: f32) -> Result<SvgPathBuilder, BuilderError> {
+        let current = self.require_started()?;
+        self.path = self.path.elliptical_arc_by(rx, ry, xrot, large_arc, sweep, dx, dy);
+        self.advance_to(geometry::resolve_point(false, current, dx, dy));
+        Ok(self)
+    }
+}
+
+impl Default for SvgPathBuilder {
+    fn default() -> SvgPathBuilder {
+        SvgPathBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_path_that_does_not_start_with_a_move_is_rejected() {
+        let err = SvgPathBuilder::new().line_to(1.0, 1.0).unwrap_err();
+        assert_eq!(err, BuilderError::MissingInitialMoveTo);
+    }
+
+    #[test]
+    fn a_smooth_cubic_after_a_non_cubic_segment_is_rejected() {
+        let err = SvgPathBuilder::new().move_to(0.0, 0.0).line_to(1.0, 0.0).unwrap()
+            .smooth_cubic_bezier_to(2.0, 1.0, 3.0, 0.0).unwrap_err();
+        assert_eq!(err, BuilderError::NotAContinuation);
+    }
+
+    #[test]
+    fn a_smooth_quadratic_after_a_non_quadratic_segment_is_rejected() {
+        let err = SvgPathBuilder::new().move_to(0.0, 0.0).line_to(1.0, 0.0).unwrap()
+            .smooth_quadratic_bezier_to(2.0, 1.0).unwrap_err();
+        assert_eq!(err, BuilderError::NotAContinuation);
+    }
+
+    #[test]
+    fn a_smooth_cubic_reflects_the_previous_curves_control_point() {
+        let builder = SvgPathBuilder::new().move_to(0.0, 0.0)
+            .cubic_bezier_to(0.0, 1.0, 1.0, 1.0, 2.0, 0.0).unwrap();
+        assert_eq!(builder.last_control_point(), Some((1.0, 1.0)));
+
+        let builder = builder.smooth_cubic_bezier_to(4.0, 1.0, 5.0, 0.0).unwrap();
+        assert_eq!(builder.current_point(), Some((5.0, 0.0)));
+        // The reflection of (1.0, 1.0) through the current point (2.0, 0.0) is (3.0, -1.0),
+        // which becomes the smooth curve's implicit first control point.
+        let path = builder.build();
+        assert_eq!(path.segments().last(), Some(&crate::Segment::CubicBezierTo {
+            abs: true, x1: 3.0, y1: -1.0, x2: 4.0, y2: 1.0, x: 5.0, y: 0.0,
+        }));
+    }
+}
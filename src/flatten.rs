@@ -0,0 +1,126 @@
+use std::f32::consts::PI;
+
+use crate::geometry::{self, AbsoluteSegment, Point};
+use crate::Path;
+
+/// Recursion cap for adaptive curve subdivision, guarding against runaway recursion when
+/// `tolerance` is zero or otherwise unreachable.
+const MAX_SUBDIVISION_DEPTH: u32 = 24;
+
+impl Path {
+    /// Converts this path's curves and arcs into connected line segments, such that every point
+    /// on the flattened polyline is within `tolerance` of the true geometry. Returns the points
+    /// of every subpath concatenated in drawing order; a `MoveTo` or `Close` command is included
+    /// as a point in the sequence so subpath boundaries can still be recovered from jumps.
+    pub fn flatten(&self, tolerance: f32) -> Vec<Point> {
+        let mut points = Vec::new();
+        for resolved in geometry::resolve(self.segments()) {
+            match resolved.segment {
+                AbsoluteSegment::MoveTo(p) | AbsoluteSegment::LineTo(p) | AbsoluteSegment::Close(p) =>
+                    points.push(p),
+                AbsoluteSegment::CubicTo(p1, p2, p3) =>
+                    flatten_cubic(resolved.from, p1, p2, p3, tolerance, 0, &mut points),
+                AbsoluteSegment::QuadraticTo(p1, p2) =>
+                    flatten_quadratic(resolved.from, p1, p2, tolerance, 0, &mut points),
+                AbsoluteSegment::ArcTo { rx, ry, xrot, large_arc, sweep, to } =>
+                    flatten_arc(resolved.from, ArcEndpoint { rx, ry, xrot, large_arc, sweep, to }, tolerance, &mut points),
+            }
+        }
+        points
+    }
+}
+
+fn flatten_cubic(p0: Point, p1: Point, p2: Point, p3: Point, tolerance: f32, depth: u32, out: &mut Vec<Point>) {
+    if depth >= MAX_SUBDIVISION_DEPTH || (distance_to_line(p1, p0, p3) <= tolerance && distance_to_line(p2, p0, p3) <= tolerance) {
+        out.push(p3);
+    } else {
+        let (left, right) = geometry::subdivide_cubic(p0, p1, p2, p3, 0.5);
+        flatten_cubic(left.0, left.1, left.2, left.3, tolerance, depth + 1, out);
+        flatten_cubic(right.0, right.1, right.2, right.3, tolerance, depth + 1, out);
+    }
+}
+
+fn flatten_quadratic(p0: Point, p1: Point, p2: Point, tolerance: f32, depth: u32, out: &mut Vec<Point>) {
+    if depth >= MAX_SUBDIVISION_DEPTH || distance_to_line(p1, p0, p2) <= tolerance {
+        out.push(p2);
+    } else {
+        let (left, right) = geometry::subdivide_quadratic(p0, p1, p2, 0.5);
+        flatten_quadratic(left.0, left.1, left.2, tolerance, depth + 1, out);
+        flatten_quadratic(right.0, right.1, right.2, tolerance, depth + 1, out);
+    }
+}
+
+/// An elliptical arc's endpoint-parameterization parameters, bundled to keep `flatten_arc`'s
+/// argument list manageable.
+struct ArcEndpoint {
+    rx: f32,
+    ry: f32,
+    xrot: f32,
+    large_arc: bool,
+    sweep: bool,
+    to: Point,
+}
+
+fn flatten_arc(from: Point, arc: ArcEndpoint, tolerance: f32, out: &mut Vec<Point>) {
+    let ArcEndpoint { rx, ry, xrot, large_arc, sweep, to } = arc;
+    let arc = match geometry::arc_to_center(from, rx, ry, xrot, large_arc, sweep, to) {
+        Some(arc) => arc,
+        // Degenerate arc (zero radius or coincident endpoints): draw as a line, per the SVG spec.
+        None => { out.push(to); return; }
+    };
+
+    let radius = arc.rx.max(arc.ry).max(f32::EPSILON);
+    let max_step = if tolerance >= radius {
+        PI
+    } else {
+        2.0 * (1.0 - tolerance / radius).clamp(-1.0, 1.0).acos()
+    };
+    let steps = ((arc.delta_angle.abs() / max_step.max(f32::EPSILON)).ceil() as usize).max(1);
+
+    for i in 1..steps {
+        let t = i as f32 / steps as f32;
+        out.push(arc.point_at_angle(arc.start_angle + arc.delta_angle * t));
+    }
+    out.push(to);
+}
+
+/// The perpendicular distance from `p` to the line through `a` and `b` (or the distance to `a`
+/// if they coincide).
+fn distance_to_line(p: Point, a: Point, b: Point) -> f32 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let len = (dx * dx + dy * dy).sqrt();
+    if len <= f32::EPSILON {
+        return ((p.0 - a.0).powi(2) + (p.1 - a.1).powi(2)).sqrt();
+    }
+    ((p.0 - a.0) * dy - (p.1 - a.1) * dx).abs() / len
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Path;
+
+    #[test]
+    fn lines_are_carried_over_unchanged() {
+        let path = Path::new().move_to(0.0, 0.0).line_to(1.0, 1.0).line_to(2.0, 0.0);
+        assert_eq!(path.flatten(0.1), vec![(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)]);
+    }
+
+    #[test]
+    fn a_cubic_with_collinear_controls_is_not_subdivided() {
+        // P1 and P2 lie exactly on the chord P0->P3, so the flatness test passes immediately.
+        let path = Path::new().move_to(0.0, 0.0).cubic_bezier_to(1.0, 0.0, 2.0, 0.0, 3.0, 0.0);
+        assert_eq!(path.flatten(0.1), vec![(0.0, 0.0), (3.0, 0.0)]);
+    }
+
+    #[test]
+    fn a_curved_cubic_is_subdivided_at_least_once() {
+        let path = Path::new().move_to(0.0, 0.0).cubic_bezier_to(0.0, 1.0, 1.0, 1.0, 1.0, 0.0);
+        assert!(path.flatten(0.01).len() > 2);
+    }
+
+    #[test]
+    fn tighter_tolerance_produces_more_points() {
+        let path = Path::new().move_to(0.0, 0.0).cubic_bezier_to(0.0, 1.0, 1.0, 1.0, 1.0, 0.0);
+        assert!(path.flatten(0.001).len() > path.flatten(0.5).len());
+    }
+}
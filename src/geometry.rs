@@ -0,0 +1,227 @@
+//! Shared machinery for interpreting a sequence of [`Segment`]s as absolute geometry: resolving
+//! relative commands and smooth-curve reflections against the tracked current point. Used by
+//! every feature that needs to walk a path's actual geometry rather than its command text.
+
+use std::f32::consts::PI;
+
+use crate::Segment;
+
+pub(crate) type Point = (f32, f32);
+
+/// A segment with all coordinates resolved to absolute space and smooth-curve control points
+/// made explicit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum AbsoluteSegment {
+    MoveTo(Point),
+    LineTo(Point),
+    CubicTo(Point, Point, Point),
+    QuadraticTo(Point, Point),
+    ArcTo { rx: f32, ry: f32, xrot: f32, large_arc: bool, sweep: bool, to: Point },
+    /// A closing line back to the start of the current subpath.
+    Close(Point),
+}
+
+impl AbsoluteSegment {
+    pub(crate) fn end_point(&self) -> Point {
+        match *self {
+            AbsoluteSegment::MoveTo(p) => p,
+            AbsoluteSegment::LineTo(p) => p,
+            AbsoluteSegment::CubicTo(_, _, p) => p,
+            AbsoluteSegment::QuadraticTo(_, p) => p,
+            AbsoluteSegment::ArcTo { to, .. } => to,
+            AbsoluteSegment::Close(p) => p,
+        }
+    }
+}
+
+/// A [`Segment`] paired with the current point at which it begins, after resolution.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Resolved {
+    pub from: Point,
+    pub segment: AbsoluteSegment,
+}
+
+/// Walks `segments`, resolving relative commands and smooth-curve reflections against the
+/// tracked current point, subpath start, and previous control point.
+pub(crate) fn resolve(segments: &[Segment]) -> Vec<Resolved> {
+    let mut current: Point = (0.0, 0.0);
+    let mut subpath_start: Point = (0.0, 0.0);
+    let mut last_cubic_control: Option<Point> = None;
+    let mut last_quadratic_control: Option<Point> = None;
+    let mut resolved = Vec::with_capacity(segments.len());
+
+    for &segment in segments {
+        let from = current;
+        let mut next_cubic_control = None;
+        let mut next_quadratic_control = None;
+
+        let absolute = match segment {
+            Segment::MoveTo { abs, x, y } => {
+                let to = resolve_point(abs, current, x, y);
+                subpath_start = to;
+                AbsoluteSegment::MoveTo(to)
+            }
+            Segment::LineTo { abs, x, y } =>
+                AbsoluteSegment::LineTo(resolve_point(abs, current, x, y)),
+            Segment::HorizontalLineTo { abs, x } =>
+                AbsoluteSegment::LineTo((if abs { x } else { current.0 + x }, current.1)),
+            Segment::VerticalLineTo { abs, y } =>
+                AbsoluteSegment::LineTo((current.0, if abs { y } else { current.1 + y })),
+            Segment::CubicBezierTo { abs, x1, y1, x2, y2, x, y } => {
+                let c2 = resolve_point(abs, current, x2, y2);
+                next_cubic_control = Some(c2);
+                AbsoluteSegment::CubicTo(resolve_point(abs, current, x1, y1), c2, resolve_point(abs, current, x, y))
+            }
+            Segment::SmoothCubicBezierTo { abs, x2, y2, x, y } => {
+                let c1 = last_cubic_control.map(|c| reflect(current, c)).unwrap_or(current);
+                let c2 = resolve_point(abs, current, x2, y2);
+                next_cubic_control = Some(c2);
+                AbsoluteSegment::CubicTo(c1, c2, resolve_point(abs, current, x, y))
+            }
+            Segment::QuadraticBezierTo { abs, x1, y1, x, y } => {
+                let c1 = resolve_point(abs, current, x1, y1);
+                next_quadratic_control = Some(c1);
+                AbsoluteSegment::QuadraticTo(c1, resolve_point(abs, current, x, y))
+            }
+            Segment::SmoothQuadraticBezierTo { abs, x, y } => {
+                let c1 = last_quadratic_control.map(|c| reflect(current, c)).unwrap_or(current);
+                next_quadratic_control = Some(c1);
+                AbsoluteSegment::QuadraticTo(c1, resolve_point(abs, current, x, y))
+            }
+            Segment::EllipticalArcTo { abs, rx, ry, xrot, large_arc, sweep, x, y } =>
+                AbsoluteSegment::ArcTo { rx, ry, xrot, large_arc, sweep, to: resolve_point(abs, current, x, y) },
+            Segment::Close => AbsoluteSegment::Close(subpath_start),
+        };
+
+        current = absolute.end_point();
+        last_cubic_control = next_cubic_control;
+        last_quadratic_control = next_quadratic_control;
+        resolved.push(Resolved { from, segment: absolute });
+    }
+
+    resolved
+}
+
+pub(crate) fn resolve_point(abs: bool, current: Point, x: f32, y: f32) -> Point {
+    if abs { (x, y) } else { (current.0 + x, current.1 + y) }
+}
+
+/// Reflects `p` through `center`, as used to compute a smooth curve's implicit control point.
+pub(crate) fn reflect(center: Point, p: Point) -> Point {
+    (2.0 * center.0 - p.0, 2.0 * center.1 - p.1)
+}
+
+/// The four control points of a cubic bezier.
+pub(crate) type CubicControlPoints = (Point, Point, Point, Point);
+
+/// Evaluates a cubic bezier with control points `p0, p1, p2, p3` at parameter `t` via de
+/// Casteljau's algorithm, also returning the two halves' control points for subdivision at `t`.
+pub(crate) fn subdivide_cubic(p0: Point, p1: Point, p2: Point, p3: Point, t: f32) -> (CubicControlPoints, CubicControlPoints) {
+    let lerp = |a: Point, b: Point| (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t);
+    let p01 = lerp(p0, p1);
+    let p12 = lerp(p1, p2);
+    let p23 = lerp(p2, p3);
+    let p012 = lerp(p01, p12);
+    let p123 = lerp(p12, p23);
+    let p0123 = lerp(p012, p123);
+    ((p0, p01, p012, p0123), (p0123, p123, p23, p3))
+}
+
+/// Subdivides a quadratic bezier with control points `p0, p1, p2` at parameter `t`.
+pub(crate) fn subdivide_quadratic(p0: Point, p1: Point, p2: Point, t: f32) -> ((Point, Point, Point), (Point, Point, Point)) {
+    let lerp = |a: Point, b: Point| (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t);
+    let p01 = lerp(p0, p1);
+    let p12 = lerp(p1, p2);
+    let p012 = lerp(p01, p12);
+    ((p0, p01, p012), (p012, p12, p2))
+}
+
+/// An elliptical arc in center parameterization, as converted from the SVG endpoint
+/// parameterization by [`arc_to_center`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct ArcCenter {
+    pub center: Point,
+    pub rx: f32,
+    pub ry: f32,
+    /// Rotation of the ellipse's x-axis, in radians.
+    pub xrot: f32,
+    /// Start angle around the (unrotated, unscaled) ellipse, in radians.
+    pub start_angle: f32,
+    /// Signed sweep angle, in radians; negative sweeps clockwise.
+    pub delta_angle: f32,
+}
+
+impl ArcCenter {
+    /// The point on the ellipse at angle `angle` (in the same parameterization as
+    /// `start_angle`).
+    pub(crate) fn point_at_angle(&self, angle: f32) -> Point {
+        let (cx, cy) = self.center;
+        let (sin_phi, cos_phi) = self.xrot.sin_cos();
+        let x = self.rx * angle.cos();
+        let y = self.ry * angle.sin();
+        (cx + x * cos_phi - y * sin_phi, cy + x * sin_phi + y * cos_phi)
+    }
+}
+
+/// Converts the SVG endpoint parameterization of an elliptical arc to center parameterization,
+/// following the construction in SVG 1.1 appendix F.6.5. Returns `None` if the arc is degenerate
+/// (zero radius, or identical endpoints), in which case it should be treated as a straight line.
+pub(crate) fn arc_to_center(from: Point, rx: f32, ry: f32, xrot_degrees: f32, large_arc: bool, sweep: bool, to: Point) -> Option<ArcCenter> {
+    let (x1, y1) = from;
+    let (x2, y2) = to;
+    if (x1, y1) == (x2, y2) {
+        return None;
+    }
+    let mut rx = rx.abs();
+    let mut ry = ry.abs();
+    if rx < f32::EPSILON || ry < f32::EPSILON {
+        return None;
+    }
+    let phi = xrot_degrees.to_radians();
+    let (sin_phi, cos_phi) = phi.sin_cos();
+
+    let dx2 = (x1 - x2) / 2.0;
+    let dy2 = (y1 - y2) / 2.0;
+    let x1p = cos_phi * dx2 + sin_phi * dy2;
+    let y1p = -sin_phi * dx2 + cos_phi * dy2;
+
+    let lambda = (x1p * x1p) / (rx * rx) + (y1p * y1p) / (ry * ry);
+    if lambda > 1.0 {
+        let scale = lambda.sqrt();
+        rx *= scale;
+        ry *= scale;
+    }
+
+    let rx2 = rx * rx;
+    let ry2 = ry * ry;
+    let num = (rx2 * ry2 - rx2 * y1p * y1p - ry2 * x1p * x1p).max(0.0);
+    let den = rx2 * y1p * y1p + ry2 * x1p * x1p;
+    let co = (num / den).sqrt() * if large_arc == sweep { -1.0 } else { 1.0 };
+    let cxp = co * rx * y1p / ry;
+    let cyp = -co * ry * x1p / rx;
+
+    let cx = cos_phi * cxp - sin_phi * cyp + (x1 + x2) / 2.0;
+    let cy = sin_phi * cxp + cos_phi * cyp + (y1 + y2) / 2.0;
+
+    let angle = |ux: f32, uy: f32, vx: f32, vy: f32| -> f32 {
+        let dot = ux * vx + uy * vy;
+        let len = ((ux * ux + uy * uy) * (vx * vx + vy * vy)).sqrt();
+        let sign = if ux * vy - uy * vx < 0.0 { -1.0 } else { 1.0 };
+        sign * (dot / len).clamp(-1.0, 1.0).acos()
+    };
+
+    let ux = (x1p - cxp) / rx;
+    let uy = (y1p - cyp) / ry;
+    let vx = (-x1p - cxp) / rx;
+    let vy = (-y1p - cyp) / ry;
+
+    let start_angle = angle(1.0, 0.0, ux, uy);
+    let mut delta_angle = angle(ux, uy, vx, vy) % (2.0 * PI);
+    if !sweep && delta_angle > 0.0 {
+        delta_angle -= 2.0 * PI;
+    } else if sweep && delta_angle < 0.0 {
+        delta_angle += 2.0 * PI;
+    }
+
+    Some(ArcCenter { center: (cx, cy), rx, ry, xrot: phi, start_angle, delta_angle })
+}
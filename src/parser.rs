@@ -0,0 +1,282 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::{Path, Segment};
+
+/// An error produced by [`Path::parse`] when the input is not valid SVG path data.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseError {
+    /// The input ended in the middle of a command, or argument data appeared before the first
+    /// command letter.
+    UnexpectedEnd,
+    /// A number could not be parsed at the given byte offset.
+    InvalidNumber { pos: usize },
+    /// An arc flag (expected to be `0` or `1`) could not be parsed at the given byte offset.
+    InvalidFlag { pos: usize },
+    /// An unrecognized command letter was found at the given byte offset.
+    UnknownCommand { command: char, pos: usize },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            ParseError::UnexpectedEnd =>
+                write!(f, "unexpected end of path data"),
+            ParseError::InvalidNumber { pos } =>
+                write!(f, "invalid number at position {}", pos),
+            ParseError::InvalidFlag { pos } =>
+                write!(f, "invalid flag at position {}", pos),
+            ParseError::UnknownCommand { command, pos } =>
+                write!(f, "unknown command '{}' at position {}", command, pos),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// A cursor over the bytes of SVG path data, providing the number/flag/command tokenization
+/// rules described by the SVG path grammar (optional separators, command letter elision,
+/// packed decimals like `1.5.5`, and unseparated arc flags like `01`).
+struct Lexer<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(s: &'a str) -> Lexer<'a> {
+        Lexer { bytes: s.as_bytes(), pos: 0 }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\r' | b'\n')) {
+            self.pos += 1;
+        }
+    }
+
+    fn skip_separators(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\r' | b'\n' | b',')) {
+            self.pos += 1;
+        }
+    }
+
+    fn is_empty(&mut self) -> bool {
+        self.skip_whitespace();
+        self.peek().is_none()
+    }
+
+    /// If the next non-whitespace byte is a command letter, consumes and returns it.
+    fn take_command(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(c) if c.is_ascii_alphabetic() => {
+                self.pos += 1;
+                Some(c)
+            }
+            _ => None,
+        }
+    }
+
+    fn number(&mut self) -> Result<f32, ParseError> {
+        self.skip_separators();
+        if self.peek().is_none() {
+            return Err(ParseError::UnexpectedEnd);
+        }
+        let start = self.pos;
+        if matches!(self.peek(), Some(b'+' | b'-')) {
+            self.pos += 1;
+        }
+        let mut saw_digit = false;
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.pos += 1;
+            saw_digit = true;
+        }
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            while matches!(self.peek(), Some(b'0'..=b'9')) {
+                self.pos += 1;
+                saw_digit = true;
+            }
+        }
+        if !saw_digit {
+            return Err(ParseError::InvalidNumber { pos: start });
+        }
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            let exponent_start = self.pos;
+            self.pos += 1;
+            if matches!(self.peek(), Some(b'+' | b'-')) {
+                self.pos += 1;
+            }
+            let mut saw_exponent_digit = false;
+            while matches!(self.peek(), Some(b'0'..=b'9')) {
+                self.pos += 1;
+                saw_exponent_digit = true;
+            }
+            if !saw_exponent_digit {
+                // Not actually an exponent (e.g. a trailing command letter); back off.
+                self.pos = exponent_start;
+            }
+        }
+        std::str::from_utf8(&self.bytes[start..self.pos]).unwrap()
+            .parse()
+            .map_err(|_| ParseError::InvalidNumber { pos: start })
+    }
+
+    fn pair(&mut self) -> Result<(f32, f32), ParseError> {
+        Ok((self.number()?, self.number()?))
+    }
+
+    /// Arc flags are a single `0` or `1` digit, which may immediately precede the next number
+    /// with no separator (e.g. `01 5 5` is the flags `0`, `1` followed by the number `5`).
+    fn flag(&mut self) -> Result<bool, ParseError> {
+        self.skip_separators();
+        match self.peek() {
+            Some(b'0') => { self.pos += 1; Ok(false) }
+            Some(b'1') => { self.pos += 1; Ok(true) }
+            None => Err(ParseError::UnexpectedEnd),
+            _ => Err(ParseError::InvalidFlag { pos: self.pos }),
+        }
+    }
+
+}
+
+impl Path {
+    /// Parse SVG path data into a [`Path`], mirroring the grammar accepted by the `d` attribute
+    /// of an SVG `<path>` element: commands may repeat implicitly when a command letter is
+    /// followed by further argument groups (with a trailing `M`/`m` group repeating as
+    /// `L`/`l`), separators between numbers are optional wherever a sign or decimal point can
+    /// disambiguate the boundary, and arc flags are single `0`/`1` digits that may be packed
+    /// against neighbouring numbers with no separator.
+    pub fn parse(s: &str) -> Result<Path, ParseError> {
+        let mut lexer = Lexer::new(s);
+        let mut segments = Vec::new();
+        let mut current_command: Option<u8> = None;
+
+        while !lexer.is_empty() {
+            let command = match lexer.take_command() {
+                Some(c) => {
+                    current_command = Some(c);
+                    c
+                }
+                None => match current_command {
+                    // A bare coordinate pair after `M`/`m` is an implicit `L`/`l`.
+                    Some(b'M') => b'L',
+                    Some(b'm') => b'l',
+                    Some(c) => c,
+                    None => return Err(ParseError::UnexpectedEnd),
+                },
+            };
+
+            let segment = match command {
+                b'M' | b'm' => {
+                    let (x, y) = lexer.pair()?;
+                    Segment::MoveTo { abs: command == b'M', x, y }
+                }
+                b'L' | b'l' => {
+                    let (x, y) = lexer.pair()?;
+                    Segment::LineTo { abs: command == b'L', x, y }
+                }
+                b'H' | b'h' =>
+                    Segment::HorizontalLineTo { abs: command == b'H', x: lexer.number()? },
+                b'V' | b'v' =>
+                    Segment::VerticalLineTo { abs: command == b'V', y: lexer.number()? },
+                b'C' | b'c' => {
+                    let (x1, y1) = lexer.pair()?;
+                    let (x2, y2) = lexer.pair()?;
+                    let (x, y) = lexer.pair()?;
+                    Segment::CubicBezierTo { abs: command == b'C', x1, y1, x2, y2, x, y }
+                }
+                b'S' | b's' => {
+                    let (x2, y2) = lexer.pair()?;
+                    let (x, y) = lexer.pair()?;
+                    Segment::SmoothCubicBezierTo { abs: command == b'S', x2, y2, x, y }
+                }
+                b'Q' | b'q' => {
+                    let (x1, y1) = lexer.pair()?;
+                    let (x, y) = lexer.pair()?;
+                    Segment::QuadraticBezierTo { abs: command == b'Q', x1, y1, x, y }
+                }
+                b'T' | b't' => {
+                    let (x, y) = lexer.pair()?;
+                    Segment::SmoothQuadraticBezierTo { abs: command == b'T', x, y }
+                }
+                b'A' | b'a' => {
+                    let rx = lexer.number()?;
+                    let ry = lexer.number()?;
+                    let xrot = lexer.number()?;
+                    let large_arc = lexer.flag()?;
+                    let sweep = lexer.flag()?;
+                    let (x, y) = lexer.pair()?;
+                    Segment::EllipticalArcTo { abs: command == b'A', rx, ry, xrot, large_arc, sweep, x, y }
+                }
+                b'Z' | b'z' => {
+                    // `Z` takes no arguments, so it can never implicitly repeat.
+                    current_command = None;
+                    Segment::Close
+                }
+                c => return Err(ParseError::UnknownCommand { command: c as char, pos: lexer.pos - 1 }),
+            };
+            segments.push(segment);
+        }
+
+        Ok(Path { inner: segments })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_pairs_after_lineto_elide_the_command_letter() {
+        let path = Path::parse("L 1 1 2 2").unwrap();
+        assert_eq!(path.segments(), &[
+            Segment::LineTo { abs: true, x: 1.0, y: 1.0 },
+            Segment::LineTo { abs: true, x: 2.0, y: 2.0 },
+        ]);
+    }
+
+    #[test]
+    fn trailing_pairs_after_moveto_become_implicit_lineto() {
+        let path = Path::parse("M 0 0 1 1").unwrap();
+        assert_eq!(path.segments(), &[
+            Segment::MoveTo { abs: true, x: 0.0, y: 0.0 },
+            Segment::LineTo { abs: true, x: 1.0, y: 1.0 },
+        ]);
+    }
+
+    #[test]
+    fn packed_decimals_split_on_the_second_decimal_point() {
+        let path = Path::parse("M0 0L1.5.5").unwrap();
+        assert_eq!(path.segments(), &[
+            Segment::MoveTo { abs: true, x: 0.0, y: 0.0 },
+            Segment::LineTo { abs: true, x: 1.5, y: 0.5 },
+        ]);
+    }
+
+    #[test]
+    fn arc_flags_pack_against_the_following_number() {
+        let path = Path::parse("M0 0A10 10 0 01 5 5").unwrap();
+        assert_eq!(path.segments(), &[
+            Segment::MoveTo { abs: true, x: 0.0, y: 0.0 },
+            Segment::EllipticalArcTo {
+                abs: true, rx: 10.0, ry: 10.0, xrot: 0.0, large_arc: false, sweep: true, x: 5.0, y: 5.0,
+            },
+        ]);
+    }
+
+    #[test]
+    fn a_bare_coordinate_pair_with_no_preceding_command_is_an_error() {
+        let err = Path::parse("1 1").unwrap_err();
+        assert_eq!(err, ParseError::UnexpectedEnd);
+    }
+
+    #[test]
+    fn unknown_command_is_an_error() {
+        let err = Path::parse("M0 0 X1 1").unwrap_err();
+        assert_eq!(err, ParseError::UnknownCommand { command: 'X', pos: 5 });
+    }
+}
@@ -0,0 +1,88 @@
+use crate::geometry::{self, AbsoluteSegment, Point};
+use crate::Path;
+
+/// Recursion cap for adaptive cubic subdivision, guarding against runaway recursion when
+/// `tolerance` is zero or otherwise unreachable.
+const MAX_SUBDIVISION_DEPTH: u32 = 24;
+
+impl Path {
+    /// Rewrites every cubic bezier segment (`C`/`c`/`S`/`s`) into one or more quadratic bezier
+    /// segments approximating it within `tolerance`, for renderers that only accept quadratics.
+    /// Lines, arcs and quadratics keep their geometry but are re-emitted in absolute form (`H`/`V`
+    /// as `L`, `T` as `Q`); the result is an equivalent absolute path.
+    pub fn to_quadratics(&self, tolerance: f32) -> Path {
+        let mut result = Path::new();
+        for resolved in geometry::resolve(self.segments()) {
+            result = match resolved.segment {
+                AbsoluteSegment::MoveTo((x, y)) => result.move_to(x, y),
+                AbsoluteSegment::LineTo((x, y)) => result.line_to(x, y),
+                AbsoluteSegment::Close(_) => result.close(),
+                AbsoluteSegment::QuadraticTo((x1, y1), (x, y)) => result.quadratic_bezier_to(x1, y1, x, y),
+                AbsoluteSegment::ArcTo { rx, ry, xrot, large_arc, sweep, to: (x, y) } =>
+                    result.elliptical_arc_to(rx, ry, xrot, large_arc, sweep, x, y),
+                AbsoluteSegment::CubicTo(p1, p2, p3) =>
+                    lower_cubic(result, resolved.from, p1, p2, p3, tolerance, 0),
+            };
+        }
+        result
+    }
+}
+
+fn lower_cubic(path: Path, p0: Point, p1: Point, p2: Point, p3: Point, tolerance: f32, depth: u32) -> Path {
+    if depth >= MAX_SUBDIVISION_DEPTH || approximation_error(p0, p1, p2, p3) <= tolerance {
+        let (qx, qy) = quadratic_control(p0, p1, p2, p3);
+        path.quadratic_bezier_to(qx, qy, p3.0, p3.1)
+    } else {
+        let (left, right) = geometry::subdivide_cubic(p0, p1, p2, p3, 0.5);
+        let path = lower_cubic(path, left.0, left.1, left.2, left.3, tolerance, depth + 1);
+        lower_cubic(path, right.0, right.1, right.2, right.3, tolerance, depth + 1)
+    }
+}
+
+/// The control point of the single quadratic bezier that best approximates the cubic bezier
+/// `p0, p1, p2, p3`: `Q = (3*(P1 + P2) - (P0 + P3)) / 4`.
+fn quadratic_control(p0: Point, p1: Point, p2: Point, p3: Point) -> Point {
+    (
+        (3.0 * (p1.0 + p2.0) - (p0.0 + p3.0)) / 4.0,
+        (3.0 * (p1.1 + p2.1) - (p0.1 + p3.1)) / 4.0,
+    )
+}
+
+/// The error bound of the single-quadratic approximation: `(sqrt(3)/36) * |P0 - 3*P1 + 3*P2 - P3|`.
+fn approximation_error(p0: Point, p1: Point, p2: Point, p3: Point) -> f32 {
+    let ex = p0.0 - 3.0 * p1.0 + 3.0 * p2.0 - p3.0;
+    let ey = p0.1 - 3.0 * p1.1 + 3.0 * p2.1 - p3.1;
+    (3f32.sqrt() / 36.0) * (ex * ex + ey * ey).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Path, Segment};
+
+    #[test]
+    fn a_cubic_with_zero_error_lowers_to_a_single_quadratic() {
+        // Control points chosen so the exact error bound is zero: P0 - 3*P1 + 3*P2 - P3 = 0.
+        let path = Path::new().move_to(0.0, 0.0).cubic_bezier_to(1.0, 0.0, 2.0, 0.0, 3.0, 0.0);
+        let lowered = path.to_quadratics(0.0);
+        assert_eq!(lowered.segments(), &[
+            Segment::MoveTo { abs: true, x: 0.0, y: 0.0 },
+            Segment::QuadraticBezierTo { abs: true, x1: 1.5, y1: 0.0, x: 3.0, y: 0.0 },
+        ]);
+    }
+
+    #[test]
+    fn lines_and_arcs_are_unchanged() {
+        let path = Path::new().move_to(0.0, 0.0).line_to(1.0, 1.0)
+            .elliptical_arc_to(5.0, 5.0, 0.0, false, true, 2.0, 2.0);
+        assert_eq!(path.to_quadratics(0.1).segments(), path.segments());
+    }
+
+    #[test]
+    fn a_curved_cubic_lowers_to_more_than_one_quadratic() {
+        let path = Path::new().move_to(0.0, 0.0).cubic_bezier_to(0.0, 1.0, 1.0, 1.0, 1.0, 0.0);
+        let quad_count = path.to_quadratics(0.001).segments().iter()
+            .filter(|s| matches!(s, Segment::QuadraticBezierTo { .. }))
+            .count();
+        assert!(quad_count > 1);
+    }
+}
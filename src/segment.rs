@@ -0,0 +1,60 @@
+use std::fmt::{self, Display, Formatter};
+
+/// A single command in an SVG path, as produced by the builder methods on [`Path`](crate::Path)
+/// or returned by [`Path::segments`](crate::Path::segments).
+///
+/// Each variant that has both an absolute and relative form (everything but [`Segment::Close`])
+/// carries an `abs` flag recording which form produced it; this lets [`Display`] reproduce the
+/// original command letter (e.g. `L` vs `l`) when serializing the path back to text.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Segment {
+    /// A `M`/`m` command: move the current point and start a new subpath.
+    MoveTo { abs: bool, x: f32, y: f32 },
+    /// An `L`/`l` command: draw a line.
+    LineTo { abs: bool, x: f32, y: f32 },
+    /// An `H`/`h` command: draw a horizontal line.
+    HorizontalLineTo { abs: bool, x: f32 },
+    /// A `V`/`v` command: draw a vertical line.
+    VerticalLineTo { abs: bool, y: f32 },
+    /// A `C`/`c` command: draw a cubic bezier curve.
+    CubicBezierTo { abs: bool, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32 },
+    /// An `S`/`s` command: draw a cubic bezier curve whose first control point is the
+    /// reflection of the previous curve's second control point.
+    SmoothCubicBezierTo { abs: bool, x2: f32, y2: f32, x: f32, y: f32 },
+    /// A `Q`/`q` command: draw a quadratic bezier curve.
+    QuadraticBezierTo { abs: bool, x1: f32, y1: f32, x: f32, y: f32 },
+    /// A `T`/`t` command: draw a quadratic bezier curve whose control point is the reflection
+    /// of the previous curve's control point.
+    SmoothQuadraticBezierTo { abs: bool, x: f32, y: f32 },
+    /// An `A`/`a` command: draw an elliptical arc.
+    EllipticalArcTo { abs: bool, rx: f32, ry: f32, xrot: f32, large_arc: bool, sweep: bool, x: f32, y: f32 },
+    /// A `Z`/`z` command: close the current subpath.
+    Close,
+}
+
+impl Display for Segment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Segment::MoveTo { abs, x, y } =>
+                write!(f, "{} {} {}", if abs { 'M' } else { 'm' }, x, y),
+            Segment::LineTo { abs, x, y } =>
+                write!(f, "{} {} {}", if abs { 'L' } else { 'l' }, x, y),
+            Segment::HorizontalLineTo { abs, x } =>
+                write!(f, "{} {}", if abs { 'H' } else { 'h' }, x),
+            Segment::VerticalLineTo { abs, y } =>
+                write!(f, "{} {}", if abs { 'V' } else { 'v' }, y),
+            Segment::CubicBezierTo { abs, x1, y1, x2, y2, x, y } =>
+                write!(f, "{} {} {}, {} {}, {} {}", if abs { 'C' } else { 'c' }, x1, y1, x2, y2, x, y),
+            Segment::SmoothCubicBezierTo { abs, x2, y2, x, y } =>
+                write!(f, "{} {} {}, {} {}", if abs { 'S' } else { 's' }, x2, y2, x, y),
+            Segment::QuadraticBezierTo { abs, x1, y1, x, y } =>
+                write!(f, "{} {} {}, {} {}", if abs { 'Q' } else { 'q' }, x1, y1, x, y),
+            Segment::SmoothQuadraticBezierTo { abs, x, y } =>
+                write!(f, "{} {} {}", if abs { 'T' } else { 't' }, x, y),
+            Segment::EllipticalArcTo { abs, rx, ry, xrot, large_arc, sweep, x, y } =>
+                write!(f, "{} {} {} {} {} {} {} {}", if abs { 'A' } else { 'a' },
+                    rx, ry, xrot, large_arc as i32, sweep as i32, x, y),
+            Segment::Close => write!(f, "Z"),
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use std::f32::consts::PI;
+
+use crate::Path;
+
+impl Path {
+    /// Create a path representing the circular arc with angle `arc_angle` radians and beginning
+    /// `start_angle` radians around the circle with center `(center_x, center_y)` and radius `radius`.
+    pub fn partial_circle(center_x: f32, center_y: f32, radius: f32, start_angle: f32, arc_angle: f32) -> Path {
+        let mut path = Path::new()
+            .move_to(center_x + radius * start_angle.cos(), center_y - radius * start_angle.sin());
+
+        let mid_angle = start_angle + arc_angle.signum() * arc_angle.abs().min(PI);
+        path = path.elliptical_arc_to(
+            radius, radius, 0f32, false, arc_angle < 0.0,
+            center_x + radius * mid_angle.cos(), center_y - radius * mid_angle.sin()
+        );
+
+        if arc_angle.abs() > PI {
+            let end_angle = start_angle + arc_angle;
+            path = path.elliptical_arc_to(
+                radius, radius, 0f32, false, arc_angle < 0.0,
+                center_x + radius * end_angle.cos(), center_y - radius * end_angle.sin()
+            );
+        }
+
+        path
+    }
+
+    /// Create a path representing the ellipse centered at `(cx, cy)` with radii `rx` and `ry`,
+    /// drawn as two half-ellipse arcs since no single arc can span a full ellipse.
+    pub fn ellipse(cx: f32, cy: f32, rx: f32, ry: f32) -> Path {
+        Path::new()
+            .move_to(cx + rx, cy)
+            .elliptical_arc_to(rx, ry, 0f32, false, true, cx - rx, cy)
+            .elliptical_arc_to(rx, ry, 0f32, false, true, cx + rx, cy)
+            .close()
+    }
+
+    /// Create a path representing the circle centered at `(cx, cy)` with radius `r`.
+    pub fn circle(cx: f32, cy: f32, r: f32) -> Path {
+        Path::ellipse(cx, cy, r, r)
+    }
+
+    /// Create a path representing the axis-aligned rectangle with top-left corner `(x, y)`,
+    /// width `w`, and height `h`.
+    pub fn rectangle(x: f32, y: f32, w: f32, h: f32) -> Path {
+        Path::new()
+            .move_to(x, y)
+            .line_to(x + w, y)
+            .line_to(x + w, y + h)
+            .line_to(x, y + h)
+            .close()
+    }
+
+    /// Create a path representing the axis-aligned rectangle with top-left corner `(x, y)`,
+    /// width `w`, height `h`, and corners rounded by an ellipse of radii `rx` and `ry`. The
+    /// radii are clamped to half the width/height so degenerate inputs stay valid.
+    pub fn rounded_rectangle(x: f32, y: f32, w: f32, h: f32, rx: f32, ry: f32) -> Path {
+        let rx = rx.clamp(0.0, w.abs() / 2.0);
+        let ry = ry.clamp(0.0, h.abs() / 2.0);
+
+        Path::new()
+            .move_to(x + rx, y)
+            .horizontal_line_to(x + w - rx)
+            .elliptical_arc_to(rx, ry, 0f32, false, true, x + w, y + ry)
+            .vertical_line_to(y + h - ry)
+            .elliptical_arc_to(rx, ry, 0f32, false, true, x + w - rx, y + h)
+            .horizontal_line_to(x + rx)
+            .elliptical_arc_to(rx, ry, 0f32, false, true, x, y + h - ry)
+            .vertical_line_to(y + ry)
+            .elliptical_arc_to(rx, ry, 0f32, false, true, x + rx, y)
+            .close()
+    }
+
+    /// Create a path representing the regular polygon with `sides` vertices on the circle
+    /// centered at `(cx, cy)` with radius `r`, with the first vertex placed `rotation` radians
+    /// around the circle. Returns an empty path if `sides` is zero.
+    pub fn regular_polygon(cx: f32, cy: f32, r: f32, sides: u32, rotation: f32) -> Path {
+        if sides == 0 {
+            return Path::new();
+        }
+
+        let vertex = |i: u32| {
+            let angle = rotation + i as f32 * 2.0 * PI / sides as f32;
+            (cx + r * angle.cos(), cy - r * angle.sin())
+        };
+
+        let (x0, y0) = vertex(0);
+        let mut path = Path::new().move_to(x0, y0);
+        for i in 1..sides {
+            let (x, y) = vertex(i);
+            path = path.line_to(x, y);
+        }
+        path.close()
+    }
+
+    /// Create a path representing a `points`-pointed star centered at `(cx, cy)`, alternating
+    /// between the given outer and inner radii, with the first point placed straight up.
+    /// Returns an empty path if `points` is zero.
+    pub fn star(cx: f32, cy: f32, outer_r: f32, inner_r: f32, points: u32) -> Path {
+        if points == 0 {
+            return Path::new();
+        }
+
+        let vertex_count = points * 2;
+        let vertex = |i: u32| {
+            let radius = if i.is_multiple_of(2) { outer_r } else { inner_r };
+            let angle = PI / 2.0 + i as f32 * PI / points as f32;
+            (cx + radius * angle.cos(), cy - radius * angle.sin())
+        };
+
+        let (x0, y0) = vertex(0);
+        let mut path = Path::new().move_to(x0, y0);
+        for i in 1..vertex_count {
+            let (x, y) = vertex(i);
+            path = path.line_to(x, y);
+        }
+        path.close()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Path, Segment};
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-3, "{} != {}", a, b);
+    }
+
+    fn arc_rx(path: &Path) -> f32 {
+        match path.segments().iter().find(|s| matches!(s, Segment::EllipticalArcTo { .. })).unwrap() {
+            Segment::EllipticalArcTo { rx, .. } => *rx,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn rectangle_visits_its_four_corners() {
+        let path = Path::rectangle(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(path.segments(), &[
+            Segment::MoveTo { abs: true, x: 1.0, y: 2.0 },
+            Segment::LineTo { abs: true, x: 4.0, y: 2.0 },
+            Segment::LineTo { abs: true, x: 4.0, y: 6.0 },
+            Segment::LineTo { abs: true, x: 1.0, y: 6.0 },
+            Segment::Close,
+        ]);
+    }
+
+    #[test]
+    fn rounded_rectangle_stays_within_the_nominal_bounding_box() {
+        let (min_x, min_y, max_x, max_y) = Path::rounded_rectangle(0.0, 0.0, 10.0, 10.0, 2.0, 2.0)
+            .bounding_box().unwrap();
+        assert_close(min_x, 0.0);
+        assert_close(min_y, 0.0);
+        assert_close(max_x, 10.0);
+        assert_close(max_y, 10.0);
+    }
+
+    #[test]
+    fn rounded_rectangle_clamps_radii_larger_than_half_the_side() {
+        let path = Path::rounded_rectangle(0.0, 0.0, 10.0, 10.0, 20.0, 20.0);
+        assert_close(arc_rx(&path), 5.0);
+    }
+
+    #[test]
+    fn rounded_rectangle_clamps_against_a_negative_width_without_panicking() {
+        let path = Path::rounded_rectangle(0.0, 0.0, -10.0, 10.0, 20.0, 2.0);
+        assert_close(arc_rx(&path), 5.0);
+    }
+
+    #[test]
+    fn regular_polygon_has_one_segment_per_side_plus_close() {
+        let path = Path::regular_polygon(0.0, 0.0, 5.0, 6, 0.0);
+        assert_eq!(path.segments().len(), 7);
+        assert!(matches!(path.segments()[0], Segment::MoveTo { .. }));
+        assert_eq!(path.segments().last(), Some(&Segment::Close));
+    }
+
+    #[test]
+    fn regular_polygon_with_zero_sides_is_empty() {
+        assert_eq!(Path::regular_polygon(0.0, 0.0, 5.0, 0, 0.0).segments(), &[]);
+    }
+
+    #[test]
+    fn star_has_two_segments_per_point_plus_close() {
+        let path = Path::star(0.0, 0.0, 5.0, 2.0, 5);
+        assert_eq!(path.segments().len(), 11);
+        assert!(matches!(path.segments()[0], Segment::MoveTo { .. }));
+        assert_eq!(path.segments().last(), Some(&Segment::Close));
+    }
+
+    #[test]
+    fn star_with_zero_points_is_empty() {
+        assert_eq!(Path::star(0.0, 0.0, 5.0, 2.0, 0).segments(), &[]);
+    }
+}
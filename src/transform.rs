@@ -0,0 +1,136 @@
+use crate::geometry::{self, AbsoluteSegment, Point};
+use crate::Path;
+
+impl Path {
+    /// Applies the 2D affine matrix `(a, b, c, d, e, f)` — mapping `(x, y)` to
+    /// `(a*x + c*y + e, b*x + d*y + f)` — to every point of this path, producing a new,
+    /// equivalent path with absolute coordinates. Elliptical arcs are transformed by
+    /// decomposing the new ellipse shape out of the matrix via its implicit 2x2 linear part,
+    /// flipping the sweep flag if the transform reverses orientation (negative determinant).
+    pub fn transform(&self, m: [f32; 6]) -> Path {
+        let [a, b, c, d, e, f] = m;
+        let determinant = a * d - b * c;
+        let point = |(x, y): Point| (a * x + c * y + e, b * x + d * y + f);
+
+        let mut result = Path::new();
+        for resolved in geometry::resolve(self.segments()) {
+            result = match resolved.segment {
+                AbsoluteSegment::MoveTo(p) => { let (x, y) = point(p); result.move_to(x, y) }
+                AbsoluteSegment::LineTo(p) => { let (x, y) = point(p); result.line_to(x, y) }
+                AbsoluteSegment::Close(_) => result.close(),
+                AbsoluteSegment::QuadraticTo(c1, p) => {
+                    let (x1, y1) = point(c1);
+                    let (x, y) = point(p);
+                    result.quadratic_bezier_to(x1, y1, x, y)
+                }
+                AbsoluteSegment::CubicTo(c1, c2, p) => {
+                    let (x1, y1) = point(c1);
+                    let (x2, y2) = point(c2);
+                    let (x, y) = point(p);
+                    result.cubic_bezier_to(x1, y1, x2, y2, x, y)
+                }
+                AbsoluteSegment::ArcTo { rx, ry, xrot, large_arc, sweep, to } => {
+                    let (x, y) = point(to);
+                    let (new_rx, new_ry, new_xrot) = transform_ellipse(rx, ry, xrot, m);
+                    let sweep = if determinant < 0.0 { !sweep } else { sweep };
+                    result.elliptical_arc_to(new_rx, new_ry, new_xrot, large_arc, sweep, x, y)
+                }
+            };
+        }
+        result
+    }
+}
+
+/// Transforms an ellipse of radii `(rx, ry)` rotated `xrot_degrees` by the linear part of `m`,
+/// returning the new `(rx, ry, xrot_degrees)`. Works by forming the matrix that maps the unit
+/// circle onto the transformed ellipse and extracting its singular values (the new radii) and
+/// left-rotation angle (the new rotation) via the closed-form 2x2 SVD.
+fn transform_ellipse(rx: f32, ry: f32, xrot_degrees: f32, [a, b, c, d, _, _]: [f32; 6]) -> (f32, f32, f32) {
+    let phi = xrot_degrees.to_radians();
+    let (sin_phi, cos_phi) = phi.sin_cos();
+
+    // M = L * R(phi) * diag(rx, ry), where L is the linear part of `m`.
+    let m00 = rx * (a * cos_phi + c * sin_phi);
+    let m01 = ry * (c * cos_phi - a * sin_phi);
+    let m10 = rx * (b * cos_phi + d * sin_phi);
+    let m11 = ry * (d * cos_phi - b * sin_phi);
+
+    let e = (m00 + m11) / 2.0;
+    let f = (m00 - m11) / 2.0;
+    let g = (m10 + m01) / 2.0;
+    let h = (m10 - m01) / 2.0;
+    let q = (e * e + h * h).sqrt();
+    let r = (f * f + g * g).sqrt();
+
+    let new_rx = q + r;
+    let new_ry = (q - r).abs();
+    let left_angle = h.atan2(e);
+    let right_angle = g.atan2(f);
+    let new_xrot = ((left_angle + right_angle) / 2.0).to_degrees();
+
+    (new_rx, new_ry, new_xrot)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Path, Segment};
+
+    fn arc_segment(path: &Path) -> Segment {
+        *path.segments().iter().find(|s| matches!(s, Segment::EllipticalArcTo { .. })).unwrap()
+    }
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-3, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn translation_moves_points_but_not_curve_shape() {
+        let path = Path::new().move_to(1.0, 1.0).line_to(2.0, 1.0);
+        let moved = path.transform([1.0, 0.0, 0.0, 1.0, 10.0, 20.0]);
+        assert_eq!(moved.segments(), &[
+            Segment::MoveTo { abs: true, x: 11.0, y: 21.0 },
+            Segment::LineTo { abs: true, x: 12.0, y: 21.0 },
+        ]);
+    }
+
+    #[test]
+    fn an_anisotropic_scale_rewrites_the_ellipses_radii() {
+        let path = Path::new().move_to(5.0, 0.0)
+            .elliptical_arc_to(2.0, 1.0, 0.0, false, true, -5.0, 0.0);
+        let scaled = path.transform([2.0, 0.0, 0.0, 3.0, 0.0, 0.0]);
+        match arc_segment(&scaled) {
+            Segment::EllipticalArcTo { rx, ry, xrot, .. } => {
+                assert_close(rx, 4.0);
+                assert_close(ry, 3.0);
+                assert_close(xrot, 0.0);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn a_rotated_ellipse_round_trips_through_an_identity_transform() {
+        let path = Path::new().move_to(5.0, 0.0)
+            .elliptical_arc_to(3.0, 1.0, 30.0, false, true, -5.0, 0.0);
+        let identity = path.transform([1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+        match arc_segment(&identity) {
+            Segment::EllipticalArcTo { rx, ry, xrot, .. } => {
+                assert_close(rx, 3.0);
+                assert_close(ry, 1.0);
+                assert_close(xrot, 30.0);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn a_reflection_flips_the_sweep_flag() {
+        let path = Path::new().move_to(5.0, 0.0)
+            .elliptical_arc_to(3.0, 1.0, 0.0, false, true, -5.0, 0.0);
+        let mirrored = path.transform([-1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+        match arc_segment(&mirrored) {
+            Segment::EllipticalArcTo { sweep, .. } => assert!(!sweep),
+            _ => unreachable!(),
+        }
+    }
+}